@@ -6,6 +6,7 @@ use sp_core::{
 };
 use structopt::StructOpt;
 
+use std::convert::TryFrom;
 use std::sync::{
     atomic::{AtomicBool, Ordering},
     mpsc, Arc,
@@ -13,13 +14,71 @@ use std::sync::{
 
 #[derive(StructOpt, Debug)]
 struct Options {
-    #[structopt(long, help = "Desired prefix of the address")]
-    prefix: String,
+    #[structopt(
+        long,
+        help = "Desired prefix of the address",
+        conflicts_with_all = &["suffix", "contains", "regex"],
+        required_unless_one = &["suffix", "contains", "regex"]
+    )]
+    prefix: Option<String>,
+
+    #[structopt(
+        long,
+        help = "Desired suffix of the address",
+        conflicts_with_all = &["prefix", "contains", "regex"],
+        required_unless_one = &["prefix", "contains", "regex"]
+    )]
+    suffix: Option<String>,
+
+    #[structopt(
+        long,
+        help = "Desired substring of the address",
+        conflicts_with_all = &["prefix", "suffix", "regex"],
+        required_unless_one = &["prefix", "suffix", "regex"]
+    )]
+    contains: Option<String>,
 
-    #[structopt(long, help = "Prefix for the secret seed", default_value = "")]
+    #[structopt(
+        long,
+        help = "Regex the address must match",
+        conflicts_with_all = &["prefix", "suffix", "contains"],
+        required_unless_one = &["prefix", "suffix", "contains"],
+        parse(try_from_str = parse_regex)
+    )]
+    regex: Option<regex::Regex>,
+
+    #[structopt(
+        long,
+        help = "Prefix for the secret seed; not supported together with --mnemonic",
+        default_value = ""
+    )]
     seed_prefix: String,
 
-    #[structopt(long, help = "Should we check for case")]
+    #[structopt(
+        long,
+        help = "Use a randomly generated BIP39 mnemonic phrase as the base secret instead of a numeric seed"
+    )]
+    mnemonic: bool,
+
+    #[structopt(
+        long,
+        help = "Number of perfect matches to find before exiting",
+        default_value = "1",
+        parse(try_from_str = parse_count)
+    )]
+    count: usize,
+
+    #[structopt(
+        long,
+        help = "Emit found keys as a JSON array on stdout instead of one seed per line"
+    )]
+    json: bool,
+
+    #[structopt(
+        long,
+        help = "Should we check for case; does not apply to --regex, which is always case-sensitive",
+        conflicts_with = "regex"
+    )]
     only_case_sensitive: bool,
 
     #[structopt(
@@ -28,12 +87,31 @@ struct Options {
         default_value = "Sr25519"
     )]
     scheme: Scheme,
+
+    #[structopt(
+        long,
+        help = "SS58 network to encode addresses for, e.g. a numeric prefix or a known name like \"polkadot\" or \"kusama\"",
+        default_value = "42",
+        parse(try_from_str = parse_network)
+    )]
+    network: Ss58AddressFormat,
+}
+
+/// `Ss58AddressFormat` only implements `TryFrom<&str>` for known names (e.g. "polkadot"),
+/// not `FromStr`, and not at all for numeric prefixes, so structopt needs a hand-rolled
+/// parser to accept both forms.
+fn parse_network(s: &str) -> Result<Ss58AddressFormat, String> {
+    if let Ok(prefix) = s.parse::<u16>() {
+        return Ok(Ss58AddressFormat::from(prefix));
+    }
+    Ss58AddressFormat::try_from(s).map_err(|_| format!("Unrecognized SS58 network: {}", s))
 }
 
 #[derive(Debug, Clone, Copy)]
 enum Scheme {
     Sr25519,
     Ed25519,
+    Ecdsa,
 }
 
 impl std::str::FromStr for Scheme {
@@ -43,6 +121,7 @@ impl std::str::FromStr for Scheme {
         match scheme.to_lowercase().as_ref() {
             "sr25519" => Ok(Scheme::Sr25519),
             "eddsa" | "ed25519" => Ok(Scheme::Ed25519),
+            "ecdsa" | "secp256k1" => Ok(Scheme::Ecdsa),
             _ => Err(format!("Unrecognized Scheme: {}", scheme)),
         }
     }
@@ -50,8 +129,9 @@ impl std::str::FromStr for Scheme {
 
 #[derive(Clone)]
 enum SchemedPair {
-    Sr25519(sp_core::sr25519::Pair),
-    Ed25519(sp_core::ed25519::Pair),
+    Sr25519(Box<sp_core::sr25519::Pair>),
+    Ed25519(Box<sp_core::ed25519::Pair>),
+    Ecdsa(Box<sp_core::ecdsa::Pair>),
 }
 
 impl SchemedPair {
@@ -59,70 +139,179 @@ impl SchemedPair {
         match self {
             SchemedPair::Sr25519(p) => AccountId32::from(p.public()),
             SchemedPair::Ed25519(p) => AccountId32::from(p.public()),
+            // `AccountId32` has no `From<ecdsa::Public>` impl: the public key is 33
+            // compressed bytes, not 32, so the account id is the blake2_256 hash of it.
+            SchemedPair::Ecdsa(p) => {
+                AccountId32::from(sp_core::blake2_256(p.public().as_ref()))
+            }
+        }
+    }
+
+    fn public_bytes(&self) -> Vec<u8> {
+        match self {
+            SchemedPair::Sr25519(p) => p.public().0.to_vec(),
+            SchemedPair::Ed25519(p) => p.public().0.to_vec(),
+            SchemedPair::Ecdsa(p) => p.public().0.to_vec(),
+        }
+    }
+
+    fn scheme_name(&self) -> &'static str {
+        match self {
+            SchemedPair::Sr25519(_) => "sr25519",
+            SchemedPair::Ed25519(_) => "ed25519",
+            SchemedPair::Ecdsa(_) => "ecdsa",
         }
     }
 
     fn derive(&self, n: u64) -> Self {
         match &self {
-            SchemedPair::Sr25519(p) => SchemedPair::Sr25519(
+            SchemedPair::Sr25519(p) => SchemedPair::Sr25519(Box::new(
                 p.derive(core::iter::once(DeriveJunction::hard(n)), None)
                     .unwrap_or_else(|infallible| match infallible {})
                     .0,
-            ),
-            SchemedPair::Ed25519(p) => SchemedPair::Ed25519(
+            )),
+            SchemedPair::Ed25519(p) => SchemedPair::Ed25519(Box::new(
+                p.derive(core::iter::once(DeriveJunction::hard(n)), None)
+                    .unwrap_or_else(|_| unreachable!("known no soft junctions"))
+                    .0,
+            )),
+            SchemedPair::Ecdsa(p) => SchemedPair::Ecdsa(Box::new(
                 p.derive(core::iter::once(DeriveJunction::hard(n)), None)
                     .unwrap_or_else(|_| unreachable!("known no soft junctions"))
                     .0,
-            ),
+            )),
         }
     }
 }
 
 impl Options {
-    pub fn is_better(&self, candidate: &Candidate, best_so_far: &Option<Candidate>) -> bool {
+    /// Builds the `Matcher` selected by whichever of `--prefix`/`--suffix`/`--contains`/`--regex`
+    /// was passed. `conflicts_with_all`/`required_unless_one` on those fields guarantee exactly
+    /// one is `Some` by the time clap has parsed the arguments.
+    pub fn matcher(&self) -> Box<dyn Matcher> {
+        if let Some(pattern) = &self.prefix {
+            return Box::new(PrefixMatcher {
+                pattern: pattern.clone(),
+                case_sensitive: self.only_case_sensitive,
+            });
+        }
+        if let Some(pattern) = &self.suffix {
+            return Box::new(SuffixMatcher {
+                pattern: pattern.clone(),
+                case_sensitive: self.only_case_sensitive,
+            });
+        }
+        if let Some(pattern) = &self.contains {
+            return Box::new(ContainsMatcher {
+                pattern: pattern.clone(),
+                case_sensitive: self.only_case_sensitive,
+            });
+        }
+
+        let regex = self.regex.clone().expect("checked above");
+        Box::new(RegexMatcher { regex })
+    }
+}
+
+/// A strategy for scoring and accepting candidate addresses.
+///
+/// `score` drives the "found new best" progress reporting: higher is closer to a match.
+/// `is_perfect` decides when the search can stop.
+trait Matcher: Send + Sync {
+    fn score(&self, address: &str) -> usize;
+    fn is_perfect(&self, address: &str) -> bool;
+
+    fn is_better(&self, candidate: &Candidate, best_so_far: &Option<Candidate>) -> bool {
         match best_so_far {
-            Some(b) => self.str_is_better(&candidate.address, &b.address),
+            Some(b) => self.score(&candidate.address) > self.score(&b.address),
             None => true,
         }
     }
+}
 
-    pub fn str_is_better(&self, new: &str, old: &str) -> bool {
-        match self
-            .loose_prefix_match(new)
-            .cmp(&self.loose_prefix_match(old))
-        {
-            std::cmp::Ordering::Greater => return true,
-            std::cmp::Ordering::Less => return false,
-            std::cmp::Ordering::Equal => {}
+struct PrefixMatcher {
+    pattern: String,
+    case_sensitive: bool,
+}
+
+impl Matcher for PrefixMatcher {
+    fn score(&self, address: &str) -> usize {
+        if self.case_sensitive {
+            matching_prefix_length(&self.pattern, address)
+        } else {
+            matching_prefix_length(&self.pattern.to_lowercase(), &address.to_lowercase())
         }
+    }
+
+    fn is_perfect(&self, address: &str) -> bool {
+        address.starts_with(&self.pattern)
+    }
+}
+
+struct SuffixMatcher {
+    pattern: String,
+    case_sensitive: bool,
+}
 
-        match self.match_count(new).cmp(&self.match_count(old)) {
-            std::cmp::Ordering::Greater => return true,
-            std::cmp::Ordering::Less => return false,
-            std::cmp::Ordering::Equal => {}
+impl Matcher for SuffixMatcher {
+    fn score(&self, address: &str) -> usize {
+        if self.case_sensitive {
+            matching_suffix_length(&self.pattern, address)
+        } else {
+            matching_suffix_length(&self.pattern.to_lowercase(), &address.to_lowercase())
         }
+    }
 
-        false
+    fn is_perfect(&self, address: &str) -> bool {
+        address.ends_with(&self.pattern)
     }
+}
+
+struct ContainsMatcher {
+    pattern: String,
+    case_sensitive: bool,
+}
 
-    fn loose_prefix_match(&self, other: &str) -> usize {
-        if self.only_case_sensitive {
-            matching_prefix_length(&self.prefix, other)
+impl Matcher for ContainsMatcher {
+    fn score(&self, address: &str) -> usize {
+        if self.case_sensitive {
+            longest_contained_prefix_length(&self.pattern, address)
         } else {
-            matching_prefix_length(&self.prefix.to_lowercase(), &other.to_lowercase())
+            longest_contained_prefix_length(&self.pattern.to_lowercase(), &address.to_lowercase())
         }
     }
 
-    fn match_count(&self, other: &str) -> usize {
-        self.prefix
-            .chars()
-            .zip(other.chars())
-            .filter(|(a, b)| a == b)
-            .count()
+    fn is_perfect(&self, address: &str) -> bool {
+        address.contains(&self.pattern)
+    }
+}
+
+struct RegexMatcher {
+    regex: regex::Regex,
+}
+
+/// Parses `--regex`, so clap reports a malformed pattern as a normal usage error instead of
+/// `Options::matcher()` panicking on it.
+fn parse_regex(s: &str) -> Result<regex::Regex, regex::Error> {
+    regex::Regex::new(s)
+}
+
+/// Parses `--count`, rejecting 0 instead of silently substituting 1 for it.
+fn parse_count(s: &str) -> Result<usize, String> {
+    match s.parse::<usize>() {
+        Ok(0) => Err("must be at least 1".to_owned()),
+        Ok(n) => Ok(n),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+impl Matcher for RegexMatcher {
+    fn score(&self, address: &str) -> usize {
+        self.regex.is_match(address) as usize
     }
 
-    fn is_perfect_match(&self, candidate: &Candidate) -> bool {
-        candidate.address.starts_with(&self.prefix)
+    fn is_perfect(&self, address: &str) -> bool {
+        self.regex.is_match(address)
     }
 }
 
@@ -130,8 +319,96 @@ fn matching_prefix_length(a: &str, b: &str) -> usize {
     a.chars().zip(b.chars()).take_while(|(a, b)| a == b).count()
 }
 
+fn matching_suffix_length(a: &str, b: &str) -> usize {
+    a.chars()
+        .rev()
+        .zip(b.chars().rev())
+        .take_while(|(a, b)| a == b)
+        .count()
+}
+
+/// Length of the longest prefix of `pattern` that appears as a contiguous substring of
+/// `haystack`, used to give `ContainsMatcher` a gradually-improving score.
+fn longest_contained_prefix_length(pattern: &str, haystack: &str) -> usize {
+    (0..=pattern.chars().count())
+        .rev()
+        .find(|&len| {
+            let prefix: String = pattern.chars().take(len).collect();
+            prefix.is_empty() || haystack.contains(&prefix)
+        })
+        .unwrap_or(0)
+}
+
+#[derive(StructOpt, Debug)]
+enum Command {
+    /// Search for a vanity address matching a pattern
+    Search(Options),
+    /// Recover the address, public key, and account id for an existing seed
+    Inspect(InspectOptions),
+}
+
+#[derive(StructOpt, Debug)]
+struct InspectOptions {
+    #[structopt(help = "Secret seed or derivation string to inspect")]
+    seed: String,
+
+    #[structopt(
+        long,
+        help = "Which scheme the seed was generated for",
+        default_value = "Sr25519"
+    )]
+    scheme: Scheme,
+
+    #[structopt(
+        long,
+        help = "SS58 network to encode the address for, e.g. a numeric prefix or a known name like \"polkadot\" or \"kusama\"",
+        default_value = "42",
+        parse(try_from_str = parse_network)
+    )]
+    network: Ss58AddressFormat,
+}
+
 fn main() {
-    let options = Arc::new(Options::from_args());
+    match Command::from_args() {
+        Command::Search(options) => search(options),
+        Command::Inspect(options) => inspect(options),
+    }
+}
+
+fn inspect(options: InspectOptions) {
+    let pair = match options.scheme {
+        Scheme::Sr25519 => SchemedPair::Sr25519(Box::new(
+            sp_core::sr25519::Pair::from_string(&options.seed, None).unwrap(),
+        )),
+        Scheme::Ed25519 => SchemedPair::Ed25519(Box::new(
+            sp_core::ed25519::Pair::from_string(&options.seed, None).unwrap(),
+        )),
+        Scheme::Ecdsa => SchemedPair::Ecdsa(Box::new(
+            sp_core::ecdsa::Pair::from_string(&options.seed, None).unwrap(),
+        )),
+    };
+    let candidate = Candidate::new(pair, options.seed, options.network);
+
+    println!("Address:    {}", candidate.address);
+    println!("Public key: 0x{}", to_hex(&candidate.pair.public_bytes()));
+    println!("Account id: 0x{}", to_hex(candidate.pair.account_id().as_ref()));
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn search(options: Options) {
+    if options.mnemonic && !options.seed_prefix.is_empty() {
+        structopt::clap::Error::with_description(
+            "--seed-prefix cannot be used together with --mnemonic",
+            structopt::clap::ErrorKind::ArgumentConflict,
+        )
+        .exit();
+    }
+
+    let options = Arc::new(options);
+    let matcher: Arc<dyn Matcher> = Arc::from(options.matcher());
 
     let should_continue = Arc::new(AtomicBool::new(true));
     let throughput = Arc::new(Throughput::default());
@@ -139,13 +416,23 @@ fn main() {
     let best_so_far = Arc::new(CowCell::new(None));
     let (better_tx, better_rx) = mpsc::sync_channel(10);
 
-    let base_candidate =
-        Candidate::base(options.scheme, &options.seed_prefix, rand::random()).unwrap();
+    let seed_mode = if options.mnemonic {
+        SeedMode::Mnemonic
+    } else {
+        SeedMode::Numeric(rand::random())
+    };
+    let base_candidate = Candidate::base(
+        options.scheme,
+        options.network,
+        &options.seed_prefix,
+        seed_mode,
+    )
+    .unwrap();
 
     let thread = {
         let best_so_far = best_so_far.clone();
         let better_tx = better_tx.clone();
-        let options = options.clone();
+        let matcher = matcher.clone();
         let should_continue = should_continue.clone();
         let throughput = throughput.clone();
         std::thread::spawn(move || {
@@ -159,7 +446,10 @@ fn main() {
                 .while_some()
                 .inspect(|_| throughput.increment())
                 .map(|n| base_candidate.derive(n))
-                .filter(|candidate| options.is_better(&candidate, &best_so_far.read()))
+                .filter(|candidate| {
+                    matcher.is_better(candidate, &best_so_far.read())
+                        || matcher.is_perfect(&candidate.address)
+                })
                 .for_each(|candidate| better_tx.send(candidate).unwrap());
         })
     };
@@ -180,24 +470,71 @@ fn main() {
         })
     };
 
+    let mut found = Vec::new();
+    let mut remaining = options.count;
+
     better_rx
         .iter()
-        .filter(|candidate| options.is_better(&candidate, &best_so_far.read()))
+        .filter(|candidate| {
+            matcher.is_better(candidate, &best_so_far.read())
+                || matcher.is_perfect(&candidate.address)
+        })
         .for_each(|candidate| {
             eprintln!("\rFound new best:               ");
             eprintln!("{}\n    {}", candidate.address, candidate.seed);
 
-            if options.is_perfect_match(&candidate) {
-                should_continue.store(false, Ordering::Relaxed);
-                println!("{}", candidate.seed);
+            // `should_continue` only stops new candidates from being generated; several
+            // in-flight perfect matches from other worker threads (or already buffered in
+            // the channel) can still arrive after `remaining` hits 0, so guard the push
+            // itself rather than relying on `should_continue` to cut things off in time.
+            if matcher.is_perfect(&candidate.address) && remaining > 0 {
+                if !options.json {
+                    println!("{}", candidate.seed);
+                }
+                found.push(FoundKey::from(&candidate));
+
+                remaining -= 1;
+                if remaining == 0 {
+                    should_continue.store(false, Ordering::Relaxed);
+                }
             }
 
-            let mut write_txn = best_so_far.write();
-            *write_txn = Some(candidate);
-            write_txn.commit();
+            if matcher.is_better(&candidate, &best_so_far.read()) {
+                let mut write_txn = best_so_far.write();
+                *write_txn = Some(candidate);
+                write_txn.commit();
+            }
         });
     thread.join().unwrap();
     monitor_thread.join().unwrap();
+
+    if options.json {
+        println!("{}", serde_json::to_string(&found).unwrap());
+    }
+}
+
+#[derive(serde::Serialize)]
+struct FoundKey {
+    address: String,
+    seed: String,
+    scheme: &'static str,
+    network: String,
+}
+
+impl From<&Candidate> for FoundKey {
+    fn from(candidate: &Candidate) -> Self {
+        FoundKey {
+            address: candidate.address.clone(),
+            seed: candidate.seed.clone(),
+            scheme: candidate.pair.scheme_name(),
+            network: candidate.network.to_string(),
+        }
+    }
+}
+
+enum SeedMode {
+    Numeric([u32; 7]),
+    Mnemonic,
 }
 
 #[derive(Clone)]
@@ -205,43 +542,75 @@ struct Candidate {
     address: String,
     pair: SchemedPair,
     seed: String,
+    network: Ss58AddressFormat,
 }
 
 impl Candidate {
-    fn base(scheme: Scheme, seed: &str, secret: [u32; 7]) -> Result<Self, SecretStringError> {
-        let bytes_suffix = secret
-            .iter()
-            .map(|n| n.to_string())
-            .collect::<Vec<_>>()
-            .join("//");
-        let seed = format!("{}//{}", &seed, bytes_suffix);
-
-        let pair = match scheme {
-            Scheme::Sr25519 => {
-                SchemedPair::Sr25519(sp_core::sr25519::Pair::from_string(&seed, None)?)
+    fn base(
+        scheme: Scheme,
+        network: Ss58AddressFormat,
+        seed_prefix: &str,
+        seed_mode: SeedMode,
+    ) -> Result<Self, SecretStringError> {
+        match seed_mode {
+            SeedMode::Numeric(secret) => {
+                let bytes_suffix = secret
+                    .iter()
+                    .map(|n| n.to_string())
+                    .collect::<Vec<_>>()
+                    .join("//");
+                let seed = format!("{}//{}", seed_prefix, bytes_suffix);
+
+                let pair = match scheme {
+                    Scheme::Sr25519 => SchemedPair::Sr25519(Box::new(
+                        sp_core::sr25519::Pair::from_string(&seed, None)?,
+                    )),
+                    Scheme::Ed25519 => SchemedPair::Ed25519(Box::new(
+                        sp_core::ed25519::Pair::from_string(&seed, None)?,
+                    )),
+                    Scheme::Ecdsa => SchemedPair::Ecdsa(Box::new(
+                        sp_core::ecdsa::Pair::from_string(&seed, None)?,
+                    )),
+                };
+
+                Ok(Candidate::new(pair, seed, network))
             }
-            Scheme::Ed25519 => {
-                SchemedPair::Ed25519(sp_core::ed25519::Pair::from_string(&seed, None)?)
+            // The base secret is a freshly generated BIP39 phrase rather than a numeric
+            // seed, so a found vanity key can be restored from words alone.
+            SeedMode::Mnemonic => {
+                let (pair, phrase) = match scheme {
+                    Scheme::Sr25519 => {
+                        let (pair, phrase, _) = sp_core::sr25519::Pair::generate_with_phrase(None);
+                        (SchemedPair::Sr25519(Box::new(pair)), phrase)
+                    }
+                    Scheme::Ed25519 => {
+                        let (pair, phrase, _) = sp_core::ed25519::Pair::generate_with_phrase(None);
+                        (SchemedPair::Ed25519(Box::new(pair)), phrase)
+                    }
+                    Scheme::Ecdsa => {
+                        let (pair, phrase, _) = sp_core::ecdsa::Pair::generate_with_phrase(None);
+                        (SchemedPair::Ecdsa(Box::new(pair)), phrase)
+                    }
+                };
+
+                Ok(Candidate::new(pair, phrase, network))
             }
-        };
-
-        Ok(Candidate::new(pair, seed))
+        }
     }
 
-    fn new(pair: SchemedPair, seed: String) -> Candidate {
+    fn new(pair: SchemedPair, seed: String, network: Ss58AddressFormat) -> Candidate {
         Candidate {
-            address: pair
-                .account_id()
-                .to_ss58check_with_version(Ss58AddressFormat::Custom(42)),
+            address: pair.account_id().to_ss58check_with_version(network),
             pair,
             seed,
+            network,
         }
     }
 
     fn derive(&self, n: u32) -> Candidate {
         let new_pair = self.pair.derive(n.into());
         let seed = format!("{}//{}", self.seed, n);
-        Candidate::new(new_pair, seed)
+        Candidate::new(new_pair, seed, self.network)
     }
 }
 
@@ -259,3 +628,91 @@ impl Throughput {
         self.count.fetch_add(1, Ordering::Relaxed);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matching_suffix_length_counts_common_suffix() {
+        assert_eq!(matching_suffix_length("abc", "xabc"), 3);
+        assert_eq!(matching_suffix_length("abc", "abx"), 0);
+        assert_eq!(matching_suffix_length("", "abc"), 0);
+    }
+
+    #[test]
+    fn longest_contained_prefix_length_finds_partial_match() {
+        assert_eq!(longest_contained_prefix_length("abc", "xxabxx"), 2);
+        assert_eq!(longest_contained_prefix_length("abc", "xxabcxx"), 3);
+        assert_eq!(longest_contained_prefix_length("abc", "xyz"), 0);
+    }
+
+    #[test]
+    fn prefix_matcher_scores_and_matches() {
+        let matcher = PrefixMatcher {
+            pattern: "ab".to_owned(),
+            case_sensitive: true,
+        };
+        assert_eq!(matcher.score("abcdef"), 2);
+        assert!(matcher.is_perfect("abcdef"));
+        assert!(!matcher.is_perfect("xabcdef"));
+    }
+
+    #[test]
+    fn prefix_matcher_case_insensitive_ignores_case() {
+        let matcher = PrefixMatcher {
+            pattern: "AB".to_owned(),
+            case_sensitive: false,
+        };
+        assert_eq!(matcher.score("abcdef"), 2);
+    }
+
+    #[test]
+    fn suffix_matcher_scores_and_matches() {
+        let matcher = SuffixMatcher {
+            pattern: "ef".to_owned(),
+            case_sensitive: true,
+        };
+        assert_eq!(matcher.score("abcdef"), 2);
+        assert!(matcher.is_perfect("abcdef"));
+        assert!(!matcher.is_perfect("abcdex"));
+    }
+
+    #[test]
+    fn contains_matcher_scores_and_matches() {
+        let matcher = ContainsMatcher {
+            pattern: "cd".to_owned(),
+            case_sensitive: true,
+        };
+        assert!(matcher.is_perfect("abcdef"));
+        assert_eq!(matcher.score("abcdef"), 2);
+        assert_eq!(matcher.score("abxxxx"), 0);
+    }
+
+    #[test]
+    fn regex_matcher_scores_and_matches() {
+        let matcher = RegexMatcher {
+            regex: regex::Regex::new("^ab").unwrap(),
+        };
+        assert!(matcher.is_perfect("abcdef"));
+        assert_eq!(matcher.score("abcdef"), 1);
+        assert_eq!(matcher.score("xbcdef"), 0);
+    }
+
+    #[test]
+    fn found_key_serializes_expected_shape() {
+        let key = FoundKey {
+            address: "5Abc".to_owned(),
+            seed: "//1".to_owned(),
+            scheme: "sr25519",
+            network: "42".to_owned(),
+        };
+
+        let json = serde_json::to_string(&key).unwrap();
+
+        assert_eq!(
+            json,
+            r#"{"address":"5Abc","seed":"//1","scheme":"sr25519","network":"42"}"#
+        );
+    }
+}